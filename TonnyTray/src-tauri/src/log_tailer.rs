@@ -0,0 +1,91 @@
+use std::io::{Read, Seek, SeekFrom};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::watch;
+use tokio::time::interval;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// A single appended log line, streamed to the frontend as it's read.
+#[derive(Debug, Clone, Serialize)]
+struct LogLine {
+    source: &'static str,
+    line: String,
+}
+
+/// Tails the whisper server and autotype client log files and streams
+/// newly-appended lines to the frontend via the `log-line` event, replacing
+/// the old `window.eval` hack.
+pub struct LogTailer {
+    stop_tx: watch::Sender<bool>,
+}
+
+impl LogTailer {
+    /// Start tailing the given log files. Each file is polled independently
+    /// on its own task.
+    pub fn start(app: AppHandle, logs: Vec<(&'static str, PathBuf)>) -> Self {
+        let (stop_tx, stop_rx) = watch::channel(false);
+
+        for (source, path) in logs {
+            let app = app.clone();
+            let mut stop_rx = stop_rx.clone();
+            tauri::async_runtime::spawn(async move {
+                let mut offset = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+                let mut ticker = interval(POLL_INTERVAL);
+
+                loop {
+                    tokio::select! {
+                        _ = ticker.tick() => {}
+                        _ = stop_rx.changed() => break,
+                    }
+                    if *stop_rx.borrow() {
+                        break;
+                    }
+
+                    let Ok(mut file) = std::fs::File::open(&path) else {
+                        continue;
+                    };
+                    let Ok(metadata) = file.metadata() else {
+                        continue;
+                    };
+                    if metadata.len() < offset {
+                        // Log was truncated/rotated; start over from the top.
+                        offset = 0;
+                    }
+                    if metadata.len() == offset {
+                        continue;
+                    }
+
+                    if file.seek(SeekFrom::Start(offset)).is_err() {
+                        continue;
+                    }
+                    let mut buf = String::new();
+                    if file.read_to_string(&mut buf).is_err() {
+                        continue;
+                    }
+                    offset = metadata.len();
+
+                    for line in buf.lines() {
+                        let _ = app.emit(
+                            "log-line",
+                            LogLine {
+                                source,
+                                line: line.to_string(),
+                            },
+                        );
+                    }
+                }
+            });
+        }
+
+        Self { stop_tx }
+    }
+
+    /// Stop all tailing tasks.
+    pub fn stop(&self) {
+        let _ = self.stop_tx.send(true);
+    }
+}
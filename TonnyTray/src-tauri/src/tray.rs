@@ -1,21 +1,278 @@
-use tauri::menu::{Menu, MenuItem};
-use tauri::{AppHandle, Manager, Wry};
+use std::sync::Mutex;
+
+use serde::Serialize;
+use tauri::menu::{CheckMenuItem, Menu, MenuItem, Submenu};
+use tauri::tray::TrayIcon;
+use tauri::{AppHandle, Emitter, Manager, Wry};
+
+/// Maximum number of transcriptions kept for the "Recent" submenu.
+pub const RECENT_TRANSCRIPTIONS_CAPACITY: usize = 10;
+
+/// A single completed transcription kept in `AppContext`'s ring buffer.
+/// `id` is a monotonically increasing counter, not a position in the
+/// buffer, so a `recent:<id>` menu click still resolves to the right entry
+/// even if older transcriptions have since been evicted.
+#[derive(Debug, Clone)]
+pub struct RecentTranscription {
+    pub id: u64,
+    pub text: String,
+}
+
+/// Push a newly completed transcription into `AppContext`'s ring buffer and
+/// immediately rebuild the "Recent" submenu from it, so the tray never goes
+/// a whole session without showing a real transcription. This is the single
+/// place that mutates the buffer — call it from the transcription pipeline
+/// instead of writing to `recent_transcriptions` directly.
+pub async fn push_recent_transcription(app: &AppHandle, text: String) {
+    let context = app.state::<crate::AppContext>();
+    {
+        let mut recent = context.recent_transcriptions.lock().await;
+        let id = context
+            .next_recent_transcription_id
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        recent.push_back(RecentTranscription { id, text });
+        while recent.len() > RECENT_TRANSCRIPTIONS_CAPACITY {
+            recent.pop_front();
+        }
+    }
+
+    let tray_state = app.state::<TrayState>();
+    let submenu = tray_state.handles.lock().unwrap().recent_submenu.clone();
+    let _ = rebuild_recent_submenu(app, &submenu, &context).await;
+}
+
+/// Rebuild the "Recent" submenu from `AppContext`'s transcription ring
+/// buffer. Called once at tray setup and again every time
+/// `push_recent_transcription` adds a new entry, so the submenu is always
+/// current without relying on a native "about to show" callback.
+pub async fn rebuild_recent_submenu(
+    app: &AppHandle,
+    submenu: &Submenu<Wry>,
+    context: &crate::AppContext,
+) -> tauri::Result<()> {
+    for item in submenu.items()? {
+        submenu.remove(&item)?;
+    }
+
+    let recent = context.recent_transcriptions.lock().await;
+    if recent.is_empty() {
+        let placeholder = MenuItem::with_id(
+            app,
+            "recent:none",
+            "(no transcriptions yet)",
+            false,
+            None::<&str>,
+        )?;
+        submenu.append(&placeholder)?;
+        return Ok(());
+    }
+
+    for entry in recent.iter() {
+        let label = truncate_for_menu(&entry.text);
+        let item = MenuItem::with_id(
+            app,
+            format!("recent:{}", entry.id),
+            label,
+            true,
+            None::<&str>,
+        )?;
+        submenu.append(&item)?;
+    }
+
+    Ok(())
+}
+
+fn truncate_for_menu(text: &str) -> String {
+    const MAX_LEN: usize = 48;
+    if text.chars().count() <= MAX_LEN {
+        text.to_string()
+    } else {
+        format!("{}…", text.chars().take(MAX_LEN).collect::<String>())
+    }
+}
+
+/// Tray icon for each recording/startup state. Images are bundled at compile
+/// time from `icons/tray-*.png`.
+fn icon_for(recording: bool, server_starting: bool) -> tauri::image::Image<'static> {
+    if recording {
+        tauri::include_image!("icons/tray-recording.png")
+    } else if server_starting {
+        tauri::include_image!("icons/tray-starting.png")
+    } else {
+        tauri::include_image!("icons/tray-idle.png")
+    }
+}
+
+/// Swap the tray icon to reflect whether the autotype client is recording
+/// and whether the whisper server is still starting up.
+pub fn set_tray_icon(app: &AppHandle, recording: bool, server_starting: bool) -> tauri::Result<()> {
+    let tray_state = app.state::<TrayState>();
+    tray_state
+        .tray_icon
+        .set_icon(Some(icon_for(recording, server_starting)))
+}
+
+/// Swap the tray icon to reflect whether the autotype client is currently
+/// capturing audio.
+pub fn set_tray_recording(app: &AppHandle, recording: bool) -> tauri::Result<()> {
+    set_tray_icon(app, recording, false)
+}
+
+/// Update the tray tooltip, e.g. to show current server health.
+pub fn set_tray_tooltip(app: &AppHandle, tooltip: &str) -> tauri::Result<()> {
+    let tray_state = app.state::<TrayState>();
+    tray_state.tray_icon.set_tooltip(Some(tooltip))
+}
+
+/// Payload emitted on the `tray-action-error` event when a tray-triggered
+/// start/stop/restart fails.
+#[derive(Debug, Clone, Serialize)]
+struct TrayActionError {
+    action: &'static str,
+    message: String,
+}
+
+/// Report a failed tray action to the user: emit a structured event for the
+/// dashboard window to render, and fall back to a native OS notification so
+/// the failure is visible even when the window is hidden.
+fn report_tray_action_error(app: &AppHandle, action: &'static str, error: impl std::fmt::Display) {
+    let message = error.to_string();
+    let _ = app.emit(
+        "tray-action-error",
+        TrayActionError {
+            action,
+            message: message.clone(),
+        },
+    );
+
+    use tauri_plugin_notification::NotificationExt;
+    let _ = app
+        .notification()
+        .builder()
+        .title("HeyMa")
+        .body(format!("{action} failed: {message}"))
+        .show();
+}
+
+/// Current state of a managed process, used to drive tray checkmarks and
+/// enabled/disabled menu items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessStatus {
+    Stopped,
+    Starting,
+    Running,
+}
+
+impl ProcessStatus {
+    fn is_running(self) -> bool {
+        matches!(self, ProcessStatus::Running)
+    }
+}
+
+/// Handles to the menu items whose checked/enabled state changes as the
+/// recording client and whisper server start and stop.
+pub struct TrayHandles {
+    pub start_recording: CheckMenuItem<Wry>,
+    pub stop_recording: CheckMenuItem<Wry>,
+    pub start_server: CheckMenuItem<Wry>,
+    pub stop_server: CheckMenuItem<Wry>,
+    pub restart_server: MenuItem<Wry>,
+    pub tail_logs: CheckMenuItem<Wry>,
+    pub toggle_autolaunch: CheckMenuItem<Wry>,
+    pub recent_submenu: Submenu<Wry>,
+}
+
+/// Tray menu, its item handles, and the `TrayIcon` itself, so icon/tooltip
+/// updates can be driven from anywhere `AppHandle` is available.
+pub struct TrayState {
+    pub menu: Menu<Wry>,
+    pub handles: Mutex<TrayHandles>,
+    pub tray_icon: TrayIcon<Wry>,
+}
+
+/// Build the `AutoLaunch` handle used to register/unregister HeyMa as a
+/// login item. Fails on platform quirks (e.g. sandboxed installs where the
+/// executable path can't be resolved); callers treat this as a best-effort
+/// feature and fall back to disabling the toggle rather than crashing.
+fn auto_launch(app: &AppHandle) -> Result<auto_launch::AutoLaunch, auto_launch::Error> {
+    let exe = std::env::current_exe().unwrap_or_default();
+    auto_launch::AutoLaunchBuilder::new()
+        .set_app_name(&app.package_info().name)
+        .set_app_path(&exe.to_string_lossy())
+        .build()
+}
 
 /// Build the system tray menu
-pub fn build_tray_menu(app: &AppHandle) -> tauri::Result<Menu<Wry>> {
+pub async fn build_tray_menu(app: &AppHandle) -> tauri::Result<(Menu<Wry>, TrayHandles)> {
     let show = MenuItem::with_id(app, "show", "Show Dashboard", true, None::<&str>)?;
     let hide = MenuItem::with_id(app, "hide", "Hide Dashboard", true, None::<&str>)?;
-    
+
     // Recording controls
-    let start_recording = MenuItem::with_id(app, "start_recording", "Start Recording", true, None::<&str>)?;
-    let stop_recording = MenuItem::with_id(app, "stop_recording", "Stop Recording", true, None::<&str>)?;
-    
+    let start_recording = CheckMenuItem::with_id(
+        app,
+        "start_recording",
+        "Start Recording",
+        true,
+        false,
+        None::<&str>,
+    )?;
+    let stop_recording = CheckMenuItem::with_id(
+        app,
+        "stop_recording",
+        "Stop Recording",
+        false,
+        false,
+        None::<&str>,
+    )?;
+
     // Server controls
-    let start_server = MenuItem::with_id(app, "start_server", "Start Server", true, None::<&str>)?;
-    let stop_server = MenuItem::with_id(app, "stop_server", "Stop Server", true, None::<&str>)?;
-    let restart_server = MenuItem::with_id(app, "restart_server", "Restart Server", true, None::<&str>)?;
-    let tail_logs = MenuItem::with_id(app, "tail_logs", "Tail Logs", true, None::<&str>)?;
-    
+    let start_server = CheckMenuItem::with_id(
+        app,
+        "start_server",
+        "Start Server",
+        true,
+        false,
+        None::<&str>,
+    )?;
+    let stop_server = CheckMenuItem::with_id(
+        app,
+        "stop_server",
+        "Stop Server",
+        false,
+        false,
+        None::<&str>,
+    )?;
+    let restart_server =
+        MenuItem::with_id(app, "restart_server", "Restart Server", false, None::<&str>)?;
+
+    let context = app.state::<crate::AppContext>();
+    let tailing = context.log_tailer.lock().await.is_some();
+    let tail_logs =
+        CheckMenuItem::with_id(app, "tail_logs", "Tail Logs", true, tailing, None::<&str>)?;
+
+    // Initialize from the OS rather than app config, so an externally removed
+    // login entry (e.g. via macOS System Settings) is reflected correctly.
+    // If auto-launch can't be initialized on this platform, the toggle is
+    // shown disabled rather than taking down the whole app.
+    let (autolaunch_available, autolaunch_enabled) = match auto_launch(app) {
+        Ok(launcher) => (true, launcher.is_enabled().unwrap_or(false)),
+        Err(err) => {
+            log::error!("auto-launch unavailable, disabling Launch at Login toggle: {err}");
+            (false, false)
+        }
+    };
+    let toggle_autolaunch = CheckMenuItem::with_id(
+        app,
+        "toggle_autolaunch",
+        "Launch at Login",
+        autolaunch_available,
+        autolaunch_enabled,
+        None::<&str>,
+    )?;
+
+    let recent_submenu = Submenu::with_id(app, "recent", "Recent", true)?;
+    rebuild_recent_submenu(app, &recent_submenu, &context).await?;
+
     let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
 
     let menu = Menu::new(app)?;
@@ -31,8 +288,92 @@ pub fn build_tray_menu(app: &AppHandle) -> tauri::Result<Menu<Wry>> {
     menu.append(&tauri::menu::PredefinedMenuItem::separator(app)?)?;
     menu.append(&tail_logs)?;
     menu.append(&tauri::menu::PredefinedMenuItem::separator(app)?)?;
+    menu.append(&recent_submenu)?;
+    menu.append(&tauri::menu::PredefinedMenuItem::separator(app)?)?;
+    menu.append(&toggle_autolaunch)?;
+    menu.append(&tauri::menu::PredefinedMenuItem::separator(app)?)?;
     menu.append(&quit)?;
-    Ok(menu)
+
+    let handles = TrayHandles {
+        start_recording,
+        stop_recording,
+        start_server,
+        stop_server,
+        restart_server,
+        tail_logs,
+        toggle_autolaunch,
+        recent_submenu,
+    };
+
+    Ok((menu, handles))
+}
+
+/// Refresh the tray's checkmarks and enabled/disabled state from the current
+/// `ProcessManager` status. Called on menu build and after every start/stop/
+/// restart so the tray never drifts from reality.
+pub async fn update_tray_state(app: &AppHandle, context: &crate::AppContext) {
+    let recording_status = context.process_manager.lock().await.autotype_status();
+    let server_status = context.process_manager.lock().await.whisper_status();
+
+    let tailing = context.log_tailer.lock().await.is_some();
+
+    let tray_state = app.state::<TrayState>();
+    let handles = tray_state.handles.lock().unwrap();
+
+    let _ = handles
+        .start_recording
+        .set_checked(recording_status.is_running());
+    let _ = handles
+        .start_recording
+        .set_enabled(!recording_status.is_running());
+    let _ = handles.stop_recording.set_checked(false);
+    let _ = handles
+        .stop_recording
+        .set_enabled(recording_status.is_running());
+
+    let _ = handles.start_server.set_checked(server_status.is_running());
+    let _ = handles
+        .start_server
+        .set_enabled(matches!(server_status, ProcessStatus::Stopped));
+    let _ = handles.stop_server.set_checked(false);
+    let _ = handles.stop_server.set_enabled(server_status.is_running());
+    let _ = handles
+        .restart_server
+        .set_enabled(server_status.is_running());
+    let _ = handles.tail_logs.set_checked(tailing);
+    drop(handles);
+
+    let _ = set_tray_icon(
+        app,
+        recording_status.is_running(),
+        matches!(server_status, ProcessStatus::Starting),
+    );
+
+    let server_tooltip = match server_status {
+        ProcessStatus::Running => format!("Whisper: running on :{}", context.state.whisper_port()),
+        ProcessStatus::Starting => "Whisper: starting...".to_string(),
+        ProcessStatus::Stopped => "Whisper: stopped".to_string(),
+    };
+    let _ = set_tray_tooltip(app, &format!("HeyMa\n{server_tooltip}"));
+}
+
+/// Subscribe to `ProcessManager`'s state-changed signal and refresh the tray
+/// on every emission, so starts/stops that don't originate from a tray click
+/// — the global recording hotkey, the whisper server exiting on its own —
+/// also keep the tray in sync instead of only reacting to our own clicks.
+/// Call this once during app setup, alongside `build_tray_menu`.
+pub fn spawn_process_status_listener(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut status_rx = {
+            let context = app.state::<crate::AppContext>();
+            context.process_manager.lock().await.subscribe()
+        };
+
+        while status_rx.changed().await.is_ok() {
+            let context = app.state::<crate::AppContext>();
+            update_tray_state(&app, &context).await;
+        }
+    });
 }
 
 /// Handle system tray events
@@ -54,7 +395,12 @@ pub fn handle_tray_event(app: &AppHandle, event: tauri::menu::MenuEvent) {
             tauri::async_runtime::spawn(async move {
                 let context = app_clone.state::<crate::AppContext>();
                 let pm = context.process_manager.lock().await;
-                let _ = pm.start_autotype_client(&context.state).await;
+                let result = pm.start_autotype_client(&context.state).await;
+                drop(pm);
+                if let Err(err) = result {
+                    report_tray_action_error(&app_clone, "start_recording", err);
+                }
+                update_tray_state(&app_clone, &context).await;
             });
         }
         "stop_recording" => {
@@ -62,7 +408,12 @@ pub fn handle_tray_event(app: &AppHandle, event: tauri::menu::MenuEvent) {
             tauri::async_runtime::spawn(async move {
                 let context = app_clone.state::<crate::AppContext>();
                 let pm = context.process_manager.lock().await;
-                let _ = pm.stop_autotype_client(&context.state).await;
+                let result = pm.stop_autotype_client(&context.state).await;
+                drop(pm);
+                if let Err(err) = result {
+                    report_tray_action_error(&app_clone, "stop_recording", err);
+                }
+                update_tray_state(&app_clone, &context).await;
             });
         }
         "start_server" => {
@@ -70,7 +421,12 @@ pub fn handle_tray_event(app: &AppHandle, event: tauri::menu::MenuEvent) {
             tauri::async_runtime::spawn(async move {
                 let context = app_clone.state::<crate::AppContext>();
                 let pm = context.process_manager.lock().await;
-                let _ = pm.start_whisper_server(&context.state).await;
+                let result = pm.start_whisper_server(&context.state).await;
+                drop(pm);
+                if let Err(err) = result {
+                    report_tray_action_error(&app_clone, "start_server", err);
+                }
+                update_tray_state(&app_clone, &context).await;
             });
         }
         "stop_server" => {
@@ -78,7 +434,12 @@ pub fn handle_tray_event(app: &AppHandle, event: tauri::menu::MenuEvent) {
             tauri::async_runtime::spawn(async move {
                 let context = app_clone.state::<crate::AppContext>();
                 let pm = context.process_manager.lock().await;
-                let _ = pm.stop_whisper_server(&context.state).await;
+                let result = pm.stop_whisper_server(&context.state).await;
+                drop(pm);
+                if let Err(err) = result {
+                    report_tray_action_error(&app_clone, "stop_server", err);
+                }
+                update_tray_state(&app_clone, &context).await;
             });
         }
         "restart_server" => {
@@ -86,19 +447,116 @@ pub fn handle_tray_event(app: &AppHandle, event: tauri::menu::MenuEvent) {
             tauri::async_runtime::spawn(async move {
                 let context = app_clone.state::<crate::AppContext>();
                 let pm = context.process_manager.lock().await;
-                let _ = pm.restart_whisper_server(&context.state).await;
+                let result = pm.restart_whisper_server(&context.state).await;
+                drop(pm);
+                if let Err(err) = result {
+                    report_tray_action_error(&app_clone, "restart_server", err);
+                }
+                update_tray_state(&app_clone, &context).await;
+            });
+        }
+        "toggle_autolaunch" => {
+            let app_clone = app.clone();
+            tauri::async_runtime::spawn(async move {
+                let context = app_clone.state::<crate::AppContext>();
+                // Hold the settings lock across read-decide-write so two
+                // rapid clicks can't both read the same stale enabled state
+                // and race on `enable()`/`disable()`.
+                let mut settings = context.settings.lock().await;
+
+                let launcher = match auto_launch(&app_clone) {
+                    Ok(launcher) => launcher,
+                    Err(err) => {
+                        report_tray_action_error(&app_clone, "toggle_autolaunch", err);
+                        return;
+                    }
+                };
+                let enabled = launcher.is_enabled().unwrap_or(false);
+                let result = if enabled {
+                    launcher.disable()
+                } else {
+                    launcher.enable()
+                };
+
+                if let Err(err) = result {
+                    report_tray_action_error(&app_clone, "toggle_autolaunch", err);
+                    return;
+                }
+
+                // Read back from the OS rather than assuming the toggle
+                // landed, so the checkbox always reflects reality.
+                let now_enabled = launcher.is_enabled().unwrap_or(!enabled);
+                settings.autolaunch_enabled = now_enabled;
+                drop(settings);
+
+                let tray_state = app_clone.state::<TrayState>();
+                let handles = tray_state.handles.lock().unwrap();
+                let _ = handles.toggle_autolaunch.set_checked(now_enabled);
             });
         }
         "tail_logs" => {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.show();
+                let _ = window.set_focus();
+            }
+
             let app_clone = app.clone();
             tauri::async_runtime::spawn(async move {
-                let _ = app_clone.get_webview_window("main").unwrap().eval("window.tauri.invoke('tail_logs')");
+                let context = app_clone.state::<crate::AppContext>();
+                let mut log_tailer = context.log_tailer.lock().await;
+
+                let now_tailing = if log_tailer.is_some() {
+                    // Already tailing; treat a second click as "stop".
+                    if let Some(tailer) = log_tailer.take() {
+                        tailer.stop();
+                    }
+                    false
+                } else {
+                    let logs = vec![
+                        ("whisper", context.state.whisper_log_path()),
+                        ("autotype", context.state.autotype_log_path()),
+                    ];
+                    *log_tailer =
+                        Some(crate::log_tailer::LogTailer::start(app_clone.clone(), logs));
+                    true
+                };
+                drop(log_tailer);
+
+                let tray_state = app_clone.state::<TrayState>();
+                let handles = tray_state.handles.lock().unwrap();
+                let _ = handles.tail_logs.set_checked(now_tailing);
             });
         }
         "quit" => {
             app.exit(0);
         }
+        id if id.starts_with("recent:") => {
+            let Some(recent_id) = id
+                .strip_prefix("recent:")
+                .and_then(|s| s.parse::<u64>().ok())
+            else {
+                return;
+            };
+
+            let app_clone = app.clone();
+            tauri::async_runtime::spawn(async move {
+                let context = app_clone.state::<crate::AppContext>();
+                let recent = context.recent_transcriptions.lock().await;
+                let Some(text) = recent
+                    .iter()
+                    .find(|entry| entry.id == recent_id)
+                    .map(|entry| entry.text.clone())
+                else {
+                    return;
+                };
+                drop(recent);
+
+                use tauri_plugin_clipboard_manager::ClipboardExt;
+                if let Err(err) = app_clone.clipboard().write_text(text) {
+                    report_tray_action_error(&app_clone, "recent_copy", err);
+                }
+            });
+        }
         _ => {}
     }
 }
-